@@ -2,7 +2,8 @@
 //!
 //! yesqlr is a Rust port of the [goyesql](https://github.com/knadh/goyesql) Go library.
 //! It allows multiple SQL queries to be defined in an `.sql` file, each separate by a specially formatted `--name: $name`
-//! accompanying every query, which the library then parses to a HashMap<$name, Query{}>.
+//! accompanying every query, which the library then parses to an insertion-ordered map of `$name` to `Query{}`,
+//! preserving the order in which queries appear in the source file.
 //! In addition, it also supports attaching arbitrary --$key: $value tags with every query
 //! This allows better organization and handling of SQL code in Rust projects.
 //!
@@ -56,6 +57,7 @@
 //!
 //! This project is licensed under the MIT License. See the [LICENSE](LICENSE) file for details.
 
+use indexmap::IndexMap;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
@@ -63,13 +65,24 @@ use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
 
+mod placeholders;
+mod statements;
+mod validate;
+#[cfg(feature = "sqlx")]
+mod verify;
+
+pub use placeholders::{Placeholder, PlaceholderStyle};
+pub use statements::split_statements;
+pub use validate::{Dialect, Options};
+#[cfg(feature = "sqlx")]
+pub use verify::{verify, ColumnKind, VerifyError};
+
 const TAG_NAME: &str = "name";
 const TAG_END: &str = "end";
 
 lazy_static! {
     static ref RE_TAG: Regex = Regex::new(r"^\s*--\s*(\w+)\s*:\s*(.+)").unwrap();
     static ref RE_COMMENT: Regex = Regex::new(r"^\s*--\s*(.*)").unwrap();
-    static ref RE_PLACEHOLDER: Regex = Regex::new(r"\$\d+").unwrap(); // Match placeholders like $1, $2.
 }
 
 
@@ -115,8 +128,39 @@ pub struct Query {
     pub tags: HashMap<String, String>,
 }
 
-// Map of query names (--name from the file) to the Query.
-pub type Queries = HashMap<String, Query>;
+impl Query {
+    /// Returns the bind placeholders used in this query's body, in order of first
+    /// appearance. See [`Placeholder`] for how numeric/anonymous/named styles are reported.
+    pub fn placeholders(&self) -> Vec<Placeholder> {
+        placeholders::scan(&self.query)
+    }
+
+    /// Rewrites this query's body so every placeholder is written in `target`'s style,
+    /// returning the rewritten SQL together with the name→index mapping assigned to each
+    /// distinct named or numeric placeholder that was converted, in first-appearance order.
+    /// Converting to `Numeric` or `Named` assigns sequential indices/synthetic names in
+    /// first-appearance order, reusing the same one for repeated named placeholders;
+    /// anonymous placeholders have no name of their own and so never appear in the map,
+    /// even though they still consume an index. Placeholders already written in `target`'s
+    /// style are left as written and do not appear in the map.
+    pub fn rewrite_placeholders(&self, target: PlaceholderStyle) -> (String, HashMap<String, usize>) {
+        placeholders::rewrite(&self.query, target)
+    }
+
+    /// Splits `query` into individual statements, for drivers that only accept one
+    /// statement per prepare. The original space-joined `query` is left untouched; see
+    /// [`split_statements`] for the splitting rules.
+    pub fn statements(&self) -> Vec<String> {
+        split_statements(&self.query)
+    }
+}
+
+// Re-exported so downstream crates can name the map type without depending on `indexmap` directly.
+pub use indexmap::IndexMap as QueryMap;
+
+/// Map of query names (`--name` from the file) to the parsed `Query`, in the order they
+/// appeared in the source file.
+pub type Queries = IndexMap<String, Query>;
 
 #[derive(Debug, PartialEq)]
 enum LineType {
@@ -246,19 +290,13 @@ pub fn parse<R: Read>(reader: R) -> Result<Queries, ParseError> {
             return Err(ParseError::EmptyQuery(format!("Query '{}' is empty", name)));
         }
     
-        // Check for correct placeholder sequence.
-        let placeholders: Vec<usize> = RE_PLACEHOLDER.find_iter(&query.query)
-            .map(|m| m.as_str()[1..].parse::<usize>().unwrap()) // Get the numeric part of placeholders like $1.
-            .collect();
-        
-        // Ensure the placeholders are in a proper sequence without duplicates or gaps.
-        for (i, &num) in placeholders.iter().enumerate() {
-            if num != i + 1 {
-                return Err(ParseError::UnmatchedPlaceholders(format!(
-                    "Query '{}' has incorrect placeholder order: expected {}, found {}",
-                    name, i + 1, num
-                )));
-            }
+        // Only numeric ($1, $2, ...) placeholders require a strict, gapless sequence;
+        // anonymous (?) and named (:name) placeholders carry no positional ordering.
+        if let Err((expected, found)) = placeholders::check_numeric_sequence(&query.query) {
+            return Err(ParseError::UnmatchedPlaceholders(format!(
+                "Query '{}' has incorrect placeholder order: expected {}, found {}",
+                name, expected, found
+            )));
         }
     }
     
@@ -266,6 +304,30 @@ pub fn parse<R: Read>(reader: R) -> Result<Queries, ParseError> {
     Ok(queries)
 }
 
+/// Parses the given bytes like [`parse`], then additionally validates every query's SQL
+/// against `opts.dialect` using a real SQL parser (behind the `sqlparser` feature),
+/// returning [`ParseError::MalformedSQL`] for the first query that fails to parse.
+///
+/// Without the `sqlparser` feature this behaves exactly like `parse` and performs no
+/// validation.
+///
+/// # Examples
+///
+/// ```rust
+/// use yesqlr::{parse_with_opts, Dialect, Options};
+///
+/// let queries = parse_with_opts(
+///     "--name: test\nSELECT 1;".as_bytes(),
+///     Options { dialect: Dialect::Postgres },
+/// )
+/// .expect("error parsing bytes");
+/// ```
+pub fn parse_with_opts<R: Read>(reader: R, opts: Options) -> Result<Queries, ParseError> {
+    let queries = parse(reader)?;
+    validate::validate(&queries, opts.dialect)?;
+    Ok(queries)
+}
+
 // Parse a single line while iterating the raw SQL bytes.
 fn parse_line(line: &str) -> ParsedLine {
     let line = line.trim();
@@ -461,6 +523,22 @@ FROM comments;
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_query_order_preserved() {
+        let sql = r#"
+-- name: third
+SELECT 3;
+-- name: first
+SELECT 1;
+-- name: second
+SELECT 2;
+"#;
+
+        let queries = parse(sql.as_bytes()).unwrap();
+        let names: Vec<&str> = queries.keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["third", "first", "second"]);
+    }
+
     #[test]
     fn test_placeholder_validation() {
         // Testing for consistent placeholder usage.
@@ -0,0 +1,314 @@
+//! Placeholder detection and cross-dialect rewriting for query bodies.
+//!
+//! A query's bind placeholders may be written in one of three styles depending on the
+//! target driver: numeric (`$1`, `$2`, ...), anonymous (`?`), or named (`:name` / `@name`).
+//! This module detects which style a query uses and can rewrite between them.
+
+use std::collections::HashMap;
+
+/// The placeholder style used in a query body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderStyle {
+    /// `$1`, `$2`, ...
+    Numeric,
+    /// `?`
+    Anonymous,
+    /// `:name` or `@name`
+    Named,
+}
+
+/// A single placeholder detected in a query body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+    pub style: PlaceholderStyle,
+    /// The digits for `Numeric` (e.g. `"1"`), the name for `Named` (e.g. `"id"`), or `"?"`
+    /// for `Anonymous`.
+    pub text: String,
+}
+
+/// Walks `sql` and calls `f(style, text, start, end)` for every placeholder found, in order
+/// of appearance, skipping over quoted string/identifier literals and `--`/`/* */` comments
+/// so a literal `'$5'` isn't mistaken for a bind. `start`/`end` are byte offsets of the
+/// placeholder's full span in `sql` (sigil included); `text` is the sigil-less value.
+fn visit_placeholders<F: FnMut(PlaceholderStyle, &str, usize, usize)>(sql: &str, mut f: F) {
+    let bytes = sql.as_bytes();
+    let len = bytes.len();
+    let mut i = 0;
+
+    while i < len {
+        let c = bytes[i];
+
+        // Skip single-quoted string literals.
+        if c == b'\'' {
+            i += 1;
+            while i < len && bytes[i] != b'\'' {
+                i += 1;
+            }
+            i = (i + 1).min(len);
+            continue;
+        }
+
+        // Skip double-quoted identifiers.
+        if c == b'"' {
+            i += 1;
+            while i < len && bytes[i] != b'"' {
+                i += 1;
+            }
+            i = (i + 1).min(len);
+            continue;
+        }
+
+        // Skip `--` line comments.
+        if c == b'-' && bytes.get(i + 1) == Some(&b'-') {
+            while i < len && bytes[i] != b'\n' {
+                i += 1;
+            }
+            continue;
+        }
+
+        // Skip `/* ... */` block comments.
+        if c == b'/' && bytes.get(i + 1) == Some(&b'*') {
+            i += 2;
+            while i + 1 < len && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                i += 1;
+            }
+            i = (i + 2).min(len);
+            continue;
+        }
+
+        // Numeric: $1, $2, ...
+        if c == b'$' {
+            let start = i + 1;
+            let mut j = start;
+            while j < len && bytes[j].is_ascii_digit() {
+                j += 1;
+            }
+            if j > start {
+                f(PlaceholderStyle::Numeric, &sql[start..j], i, j);
+                i = j;
+                continue;
+            }
+        }
+
+        // Anonymous: ?
+        if c == b'?' {
+            f(PlaceholderStyle::Anonymous, "?", i, i + 1);
+            i += 1;
+            continue;
+        }
+
+        // Named: :name or @name. `::` is a Postgres cast, not a placeholder, so skip it.
+        if c == b':' || c == b'@' {
+            if c == b':' && bytes.get(i + 1) == Some(&b':') {
+                i += 2;
+                continue;
+            }
+            let start = i + 1;
+            let mut j = start;
+            while j < len && (bytes[j].is_ascii_alphanumeric() || bytes[j] == b'_') {
+                j += 1;
+            }
+            if j > start {
+                f(PlaceholderStyle::Named, &sql[start..j], i, j);
+                i = j;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+}
+
+/// Scans `query` for placeholders, in order of first appearance. `Numeric` and `Named`
+/// placeholders are deduplicated by their digits/name; `Anonymous` placeholders have no
+/// identity of their own, so every occurrence is kept.
+pub(crate) fn scan(query: &str) -> Vec<Placeholder> {
+    let mut seen_numeric = std::collections::HashSet::new();
+    let mut seen_named = std::collections::HashSet::new();
+    let mut out = Vec::new();
+
+    visit_placeholders(query, |style, text, _start, _end| match style {
+        PlaceholderStyle::Numeric => {
+            if seen_numeric.insert(text.to_string()) {
+                out.push(Placeholder { style, text: text.to_string() });
+            }
+        }
+        PlaceholderStyle::Named => {
+            if seen_named.insert(text.to_string()) {
+                out.push(Placeholder { style, text: text.to_string() });
+            }
+        }
+        PlaceholderStyle::Anonymous => {
+            out.push(Placeholder { style, text: text.to_string() });
+        }
+    });
+
+    out
+}
+
+/// Validates that any `Numeric` placeholders in `query` are used in strict `$1, $2, ...`
+/// sequence with no gaps or out-of-order reuse, returning the first `(expected, found)`
+/// mismatch (`found` as its original text, since a placeholder's digits aren't guaranteed
+/// to fit in a `usize`). `Anonymous` and `Named` placeholders carry no positional ordering
+/// requirement and are ignored.
+pub(crate) fn check_numeric_sequence(query: &str) -> Result<(), (usize, String)> {
+    let mut numbers = Vec::new();
+    visit_placeholders(query, |style, text, _start, _end| {
+        if style == PlaceholderStyle::Numeric {
+            numbers.push(text.to_string());
+        }
+    });
+
+    for (i, text) in numbers.iter().enumerate() {
+        match text.parse::<usize>() {
+            Ok(num) if num == i + 1 => continue,
+            _ => return Err((i + 1, text.clone())),
+        }
+    }
+    Ok(())
+}
+
+/// Rewrites every placeholder in `query` into `target`'s style, returning the rewritten SQL
+/// together with the name→index mapping assigned to each distinct named or numeric
+/// placeholder that was converted, in first-appearance order (anonymous placeholders have
+/// no name of their own and so never appear in the map, even though they still consume an
+/// index). Placeholders already written in `target`'s style are copied as written: they
+/// take no index and do not appear in the map.
+///
+/// - To `Anonymous`: every placeholder becomes `?`.
+/// - To `Numeric`: each distinct non-numeric placeholder is assigned a sequential number,
+///   starting at `$1`, in first-appearance order; repeats of the same named placeholder
+///   reuse that number. Existing `$N` placeholders are kept as written, and the numbering
+///   does not skip the numbers they already use.
+/// - To `Named`: placeholders already written as `:name`/`@name` keep their name; numeric
+///   and anonymous placeholders are assigned synthetic names `p1`, `p2`, ... in
+///   first-appearance order.
+pub(crate) fn rewrite(query: &str, target: PlaceholderStyle) -> (String, HashMap<String, usize>) {
+    let mut numbered: HashMap<String, usize> = HashMap::new();
+    let mut next = 1usize;
+    let mut out = String::new();
+    let mut last_end = 0;
+
+    visit_placeholders(query, |style, text, start, end| {
+        out.push_str(&query[last_end..start]);
+        last_end = end;
+
+        if style == target {
+            out.push_str(&query[start..end]);
+            return;
+        }
+
+        let n = if style == PlaceholderStyle::Anonymous {
+            let n = next;
+            next += 1;
+            n
+        } else {
+            *numbered.entry(text.to_string()).or_insert_with(|| {
+                let n = next;
+                next += 1;
+                n
+            })
+        };
+
+        match target {
+            PlaceholderStyle::Anonymous => out.push('?'),
+            PlaceholderStyle::Numeric => {
+                out.push('$');
+                out.push_str(&n.to_string());
+            }
+            PlaceholderStyle::Named => {
+                out.push_str(":p");
+                out.push_str(&n.to_string());
+            }
+        }
+    });
+    out.push_str(&query[last_end..]);
+    (out, numbered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_numeric_deduped_in_order() {
+        let placeholders = scan("SELECT * FROM users WHERE id = $2 OR id = $1 OR id = $2");
+        assert_eq!(
+            placeholders,
+            vec![
+                Placeholder { style: PlaceholderStyle::Numeric, text: "2".to_string() },
+                Placeholder { style: PlaceholderStyle::Numeric, text: "1".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_named_in_first_seen_order() {
+        let placeholders = scan("INSERT INTO users (name, email) VALUES (:name, :email)");
+        assert_eq!(
+            placeholders,
+            vec![
+                Placeholder { style: PlaceholderStyle::Named, text: "name".to_string() },
+                Placeholder { style: PlaceholderStyle::Named, text: "email".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_anonymous_keeps_every_occurrence() {
+        let placeholders = scan("SELECT * FROM users WHERE id = ? AND name = ?");
+        assert_eq!(placeholders.len(), 2);
+        assert!(placeholders.iter().all(|p| p.style == PlaceholderStyle::Anonymous));
+    }
+
+    #[test]
+    fn test_check_numeric_sequence_ignores_other_styles() {
+        assert!(check_numeric_sequence("SELECT * FROM users WHERE id = ?").is_ok());
+        assert!(check_numeric_sequence("SELECT * FROM users WHERE id = :id").is_ok());
+    }
+
+    #[test]
+    fn test_check_numeric_sequence_rejects_gaps() {
+        assert_eq!(
+            check_numeric_sequence("SELECT * FROM users WHERE id = $1 AND email = $3"),
+            Err((2, "3".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_check_numeric_sequence_rejects_overflowing_digits_without_panicking() {
+        assert_eq!(
+            check_numeric_sequence("SELECT $99999999999999999999"),
+            Err((1, "99999999999999999999".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_rewrite_numeric_to_anonymous() {
+        let (rewritten, mapping) = rewrite(
+            "SELECT * FROM users WHERE id = $1 AND email = $2",
+            PlaceholderStyle::Anonymous,
+        );
+        assert_eq!(rewritten, "SELECT * FROM users WHERE id = ? AND email = ?");
+        assert_eq!(
+            mapping,
+            HashMap::from([("1".to_string(), 1), ("2".to_string(), 2)])
+        );
+    }
+
+    #[test]
+    fn test_rewrite_named_to_numeric_reuses_index_for_repeats() {
+        let (rewritten, mapping) = rewrite(
+            "SELECT * FROM users WHERE id = :id OR parent_id = :id OR email = :email",
+            PlaceholderStyle::Numeric,
+        );
+        assert_eq!(
+            rewritten,
+            "SELECT * FROM users WHERE id = $1 OR parent_id = $1 OR email = $2"
+        );
+        assert_eq!(
+            mapping,
+            HashMap::from([("id".to_string(), 1), ("email".to_string(), 2)])
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! Optional SQL-syntax validation, run as a post-pass over parsed queries, behind the
+//! `sqlparser` feature. SQL syntax is dialect-specific (Postgres dollar-quoting, BigQuery
+//! raw strings, etc.), so callers pick the dialect their queries target.
+
+use crate::{ParseError, Queries};
+
+/// The SQL dialect to validate query bodies against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    Postgres,
+    MySQL,
+    SQLite,
+    Ansi,
+    BigQuery,
+}
+
+/// Options for [`crate::parse_with_opts`].
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// The dialect used to validate query bodies when the `sqlparser` feature is enabled.
+    /// Ignored otherwise.
+    pub dialect: Dialect,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options { dialect: Dialect::Ansi }
+    }
+}
+
+#[cfg(feature = "sqlparser")]
+pub(crate) fn validate(queries: &Queries, dialect: Dialect) -> Result<(), ParseError> {
+    use sqlparser::dialect::{
+        AnsiDialect, BigQueryDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect,
+    };
+    use sqlparser::parser::Parser;
+
+    for (name, query) in queries {
+        let result = match dialect {
+            Dialect::Postgres => Parser::parse_sql(&PostgreSqlDialect {}, &query.query),
+            Dialect::MySQL => Parser::parse_sql(&MySqlDialect {}, &query.query),
+            Dialect::SQLite => Parser::parse_sql(&SQLiteDialect {}, &query.query),
+            Dialect::Ansi => Parser::parse_sql(&AnsiDialect {}, &query.query),
+            Dialect::BigQuery => Parser::parse_sql(&BigQueryDialect {}, &query.query),
+        };
+        if let Err(e) = result {
+            return Err(ParseError::MalformedSQL(format!("'{}': {}", name, e)));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "sqlparser"))]
+pub(crate) fn validate(_queries: &Queries, _dialect: Dialect) -> Result<(), ParseError> {
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sqlparser"))]
+mod tests {
+    use super::*;
+    use crate::parse_with_opts;
+
+    #[test]
+    fn test_malformed_sql_is_rejected() {
+        let sql = "-- name: broken\nSELEC * FROM users;";
+        let result = parse_with_opts(sql.as_bytes(), Options { dialect: Dialect::Ansi });
+        assert!(matches!(result, Err(ParseError::MalformedSQL(_))));
+    }
+
+    #[test]
+    fn test_well_formed_sql_passes_validation() {
+        let sql = "-- name: ok\nSELECT * FROM users WHERE id = $1;";
+        let result = parse_with_opts(sql.as_bytes(), Options { dialect: Dialect::Postgres });
+        assert!(result.is_ok());
+    }
+}
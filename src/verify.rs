@@ -0,0 +1,263 @@
+//! Turns a `.sql` file into an executable contract: runs each parsed query against a real
+//! connection and checks its declared `-- params`/`-- result`/`-- rowcount` tags against
+//! what actually comes back, in the spirit of sqllogictest. Requires the `sqlx` feature.
+//!
+//! ```text
+//! -- name: get_user
+//! -- params: 1, 'a@b.com'
+//! -- result: I T
+//! -- rowcount: 1
+//! SELECT id, email FROM users WHERE id = $1 AND email = $2;
+//! ```
+
+use crate::Queries;
+use sqlx::{Column, Row, TypeInfo};
+
+/// A declared column type from a `-- result: I T R` tag. `I`=integer, `T`=text, `R`=float,
+/// `?`=any (unchecked).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Integer,
+    Text,
+    Real,
+    Any,
+}
+
+/// A single mismatch between a query's declared tags and what running it actually returned.
+#[derive(Debug)]
+pub struct VerifyError {
+    /// The query name (`--name` from the file) this mismatch belongs to.
+    pub query: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "query '{}': {}", self.query, self.message)
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// A single bind value parsed out of a `-- params` tag.
+#[derive(Debug, Clone, PartialEq)]
+enum ParamValue {
+    Int(i64),
+    Real(f64),
+    Text(String),
+}
+
+/// Runs every query in `queries` against `pool`, checking the `-- params`, `-- result`, and
+/// `-- rowcount` tags (whichever are present) against the actual result set. All mismatches
+/// are collected rather than stopping at the first one.
+pub async fn verify(queries: &Queries, pool: &sqlx::AnyPool) -> Vec<VerifyError> {
+    let mut errors = Vec::new();
+
+    for (name, query) in queries {
+        let params = match query.tags.get("params") {
+            Some(raw) => match parse_params(raw) {
+                Ok(params) => params,
+                Err(message) => {
+                    errors.push(VerifyError { query: name.clone(), message });
+                    continue;
+                }
+            },
+            None => Vec::new(),
+        };
+        let expected_columns = query.tags.get("result").map(|raw| parse_result(raw));
+        let expected_rowcount = query.tags.get("rowcount").and_then(|raw| raw.trim().parse::<usize>().ok());
+
+        let mut q = sqlx::query(&query.query);
+        for param in &params {
+            q = bind_param(q, param);
+        }
+
+        match q.fetch_all(pool).await {
+            Ok(rows) => {
+                if let Some(expected) = expected_rowcount {
+                    if rows.len() != expected {
+                        errors.push(VerifyError {
+                            query: name.clone(),
+                            message: format!("expected {} row(s), got {}", expected, rows.len()),
+                        });
+                    }
+                }
+                if let Some(expected_kinds) = &expected_columns {
+                    if let Some(row) = rows.first() {
+                        check_column_kinds(name, row, expected_kinds, &mut errors);
+                    }
+                }
+            }
+            Err(e) => {
+                errors.push(VerifyError {
+                    query: name.clone(),
+                    message: format!("query failed to execute: {}", e),
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+fn bind_param<'q>(
+    q: sqlx::query::Query<'q, sqlx::Any, sqlx::any::AnyArguments<'q>>,
+    param: &'q ParamValue,
+) -> sqlx::query::Query<'q, sqlx::Any, sqlx::any::AnyArguments<'q>> {
+    match param {
+        ParamValue::Int(i) => q.bind(*i),
+        ParamValue::Real(f) => q.bind(*f),
+        ParamValue::Text(s) => q.bind(s.as_str()),
+    }
+}
+
+fn check_column_kinds(
+    name: &str,
+    row: &sqlx::any::AnyRow,
+    expected: &[ColumnKind],
+    errors: &mut Vec<VerifyError>,
+) {
+    let columns = row.columns();
+    if columns.len() != expected.len() {
+        errors.push(VerifyError {
+            query: name.to_string(),
+            message: format!("expected {} column(s), got {}", expected.len(), columns.len()),
+        });
+        return;
+    }
+
+    for (i, (column, expected_kind)) in columns.iter().zip(expected).enumerate() {
+        if *expected_kind == ColumnKind::Any {
+            continue;
+        }
+        let type_name = column.type_info().name();
+        if classify_type_name(type_name) != *expected_kind {
+            errors.push(VerifyError {
+                query: name.to_string(),
+                message: format!(
+                    "column {} expected {:?}, found SQL type '{}'",
+                    i, expected_kind, type_name
+                ),
+            });
+        }
+    }
+}
+
+/// Maps a driver-reported SQL type name to the closest [`ColumnKind`], by substring match
+/// since drivers spell types differently (`INT4` vs `INTEGER`, `VARCHAR` vs `TEXT`, ...).
+fn classify_type_name(type_name: &str) -> ColumnKind {
+    let upper = type_name.to_ascii_uppercase();
+    if upper.contains("INT") {
+        ColumnKind::Integer
+    } else if upper.contains("CHAR") || upper.contains("TEXT") || upper.contains("CLOB") {
+        ColumnKind::Text
+    } else if upper.contains("FLOAT")
+        || upper.contains("DOUBLE")
+        || upper.contains("REAL")
+        || upper.contains("NUMERIC")
+        || upper.contains("DECIMAL")
+    {
+        ColumnKind::Real
+    } else {
+        ColumnKind::Any
+    }
+}
+
+/// Parses a `-- params: 1, 'a@b.com'` tag value into bind values, in order. Single-quoted
+/// tokens become text binds; everything else is parsed as an integer, then a float.
+fn parse_params(raw: &str) -> Result<Vec<ParamValue>, String> {
+    split_params(raw)
+        .into_iter()
+        .map(|token| {
+            let token = token.trim();
+            if token.len() >= 2 && token.starts_with('\'') && token.ends_with('\'') {
+                Ok(ParamValue::Text(token[1..token.len() - 1].to_string()))
+            } else if let Ok(i) = token.parse::<i64>() {
+                Ok(ParamValue::Int(i))
+            } else if let Ok(f) = token.parse::<f64>() {
+                Ok(ParamValue::Real(f))
+            } else {
+                Err(format!("invalid literal in params tag: '{}'", token))
+            }
+        })
+        .collect()
+}
+
+/// Splits `raw` on commas, keeping commas inside single-quoted strings intact.
+fn split_params(raw: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in raw.chars() {
+        match c {
+            '\'' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            ',' if !in_quotes => tokens.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() || !tokens.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Parses a `-- result: I T R` tag value into expected column kinds, in column order.
+fn parse_result(raw: &str) -> Vec<ColumnKind> {
+    raw.split_whitespace()
+        .map(|tok| match tok {
+            "I" => ColumnKind::Integer,
+            "T" => ColumnKind::Text,
+            "R" => ColumnKind::Real,
+            _ => ColumnKind::Any,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_params_mixed_types() {
+        let params = parse_params("1, 'a@b.com', 2.5").unwrap();
+        assert_eq!(
+            params,
+            vec![
+                ParamValue::Int(1),
+                ParamValue::Text("a@b.com".to_string()),
+                ParamValue::Real(2.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_params_comma_inside_quotes_is_not_a_split() {
+        let params = parse_params("'Smith, John'").unwrap();
+        assert_eq!(params, vec![ParamValue::Text("Smith, John".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_params_rejects_invalid_literal() {
+        assert!(parse_params("not_quoted").is_err());
+    }
+
+    #[test]
+    fn test_parse_result_kinds() {
+        assert_eq!(
+            parse_result("I T R ?"),
+            vec![ColumnKind::Integer, ColumnKind::Text, ColumnKind::Real, ColumnKind::Any]
+        );
+    }
+
+    #[test]
+    fn test_classify_type_name() {
+        assert_eq!(classify_type_name("INT4"), ColumnKind::Integer);
+        assert_eq!(classify_type_name("VARCHAR"), ColumnKind::Text);
+        assert_eq!(classify_type_name("DOUBLE PRECISION"), ColumnKind::Real);
+        assert_eq!(classify_type_name("BYTEA"), ColumnKind::Any);
+    }
+}
@@ -0,0 +1,144 @@
+//! Structured parse errors with byte/line/column spans, so callers can point users at the
+//! exact spot in a `.sql` file that failed to parse.
+
+use std::fmt;
+
+/// A byte span into the original source, together with the 1-based line and column of its
+/// start, for diagnostics and caret-style rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// The kind of problem encountered while parsing, for callers that want to match on the
+/// failure programmatically instead of scraping the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A query body, or a tag other than `name`, was found before any `-- name:` tag
+    /// introduced a query.
+    MissingName,
+    /// The same query name was declared more than once.
+    DuplicateName,
+    /// The same tag was declared more than once for a query.
+    DuplicateTag,
+    /// A query name was declared but no SQL body followed it.
+    MissingQuery,
+    /// The underlying reader or file failed.
+    Io,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            ErrorKind::MissingName => "missing name tag",
+            ErrorKind::DuplicateName => "duplicate query name",
+            ErrorKind::DuplicateTag => "duplicate tag",
+            ErrorKind::MissingQuery => "missing query",
+            ErrorKind::Io => "io error",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Represents a parse error, with enough structure to either print a one-line message or
+/// render a source-annotated diagnostic.
+#[derive(Debug)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    pub message: String,
+    /// The query the error was raised for, when one was already known.
+    pub query: Option<String>,
+    /// The location in the source the error applies to, when the error originated from a
+    /// specific line (IO errors have no span).
+    pub span: Option<Span>,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        ParseError {
+            kind,
+            message: message.into(),
+            query: None,
+            span: None,
+        }
+    }
+
+    pub(crate) fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub(crate) fn with_query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.span {
+            Some(span) => write!(
+                f,
+                "{} at line {}, column {}: {}",
+                self.kind, span.line, span.column, self.message
+            ),
+            None => write!(f, "{}: {}", self.kind, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "codespan-errors")]
+impl ParseError {
+    /// Renders this error as a source-annotated diagnostic, with a caret pointing at the
+    /// offending span, using `codespan-reporting`.
+    pub fn render(&self, source_name: &str, source: &str) -> String {
+        use codespan_reporting::diagnostic::{Diagnostic, Label};
+        use codespan_reporting::files::SimpleFiles;
+        use codespan_reporting::term::{
+            self,
+            termcolor::{Buffer, ColorChoice},
+        };
+
+        let mut files = SimpleFiles::new();
+        let file_id = files.add(source_name, source);
+
+        let mut diagnostic = Diagnostic::error().with_message(self.message.clone());
+        if let Some(span) = self.span {
+            diagnostic = diagnostic
+                .with_labels(vec![Label::primary(file_id, span.start..span.end)
+                    .with_message(self.kind.to_string())]);
+        }
+
+        let mut buffer = Buffer::no_color();
+        let _ = ColorChoice::Never;
+        let config = term::Config::default();
+        term::emit(&mut buffer, &config, &files, &diagnostic).expect("failed to render diagnostic");
+        String::from_utf8_lossy(buffer.as_slice()).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_without_span() {
+        let err = ParseError::new(ErrorKind::Io, "file not found");
+        assert_eq!(err.to_string(), "io error: file not found");
+    }
+
+    #[test]
+    fn test_display_with_span() {
+        let err = ParseError::new(ErrorKind::MissingName, "query without a name")
+            .with_span(Span { start: 10, end: 20, line: 3, column: 5 });
+        assert_eq!(
+            err.to_string(),
+            "missing name tag at line 3, column 5: query without a name"
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! yesqlr is a Rust port of the [goyesql](https://github.com/knadh/goyesql) Go library.
 //! It allows multiple SQL queries to be defined in an `.sql` file, each separate by a specially formatted `--name: $name`
-//! accompanying every query, which the library then parses to a HashMap<$name, Query{}>.
+//! accompanying every query, which the library then parses to an insertion-ordered map of `$name` to `Query{}`,
+//! preserving the order in which queries appear in the source file.
 //! In addition, it also supports attaching arbitrary --$key: $value tags with every query
 //! This allows better organization and handling of SQL code in Rust projects.
 //!
@@ -55,6 +56,7 @@
 //!
 //! This project is licensed under the MIT License. See the [LICENSE](LICENSE) file for details.
 
+use indexmap::IndexMap;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
@@ -62,6 +64,14 @@ use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
 
+mod error;
+mod params;
+mod statements;
+
+pub use error::{ErrorKind, ParseError, Span};
+pub use params::{extract_params, Param, ParamStyle, PlaceholderDialect};
+pub use statements::split_statements;
+
 const TAG_NAME: &str = "name";
 
 lazy_static! {
@@ -69,32 +79,40 @@ lazy_static! {
     static ref RE_COMMENT: Regex = Regex::new(r"^\s*--\s*(.*)").unwrap();
 }
 
-// Represents an parse error.
-#[derive(Debug)]
-pub struct ParseError(String);
-
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
-
-impl std::error::Error for ParseError {}
-
 /// Represents a single SQL query parsed from the file with associated tags.
 ///
 /// # Fields
 ///
 /// - `query`: The SQL query string.
 /// - `tags`: A map of tag names to their corresponding values.
+/// - `params`: The bind parameters detected in `query`, in first-seen order.
+/// - `placeholder_dialect`: The placeholder style `params` was detected in, or `None` if
+///   the query has no placeholders. `Some(PlaceholderDialect::Mixed)` means more than one
+///   style was used, which most drivers reject.
 #[derive(Debug, Clone, Default)]
 pub struct Query {
     pub query: String,
     pub tags: HashMap<String, String>,
+    pub params: Vec<Param>,
+    pub placeholder_dialect: Option<PlaceholderDialect>,
 }
 
-// Map of query names (--name from the file) to the Query.
-pub type Queries = HashMap<String, Query>;
+impl Query {
+    /// Splits `query` into individual statements, for drivers that only accept one
+    /// prepared statement at a time. `query` itself is left untouched, so callers that
+    /// want a single concatenated string can keep using it as before. See
+    /// [`split_statements`] for the splitting rules.
+    pub fn statements(&self) -> Vec<String> {
+        split_statements(&self.query)
+    }
+}
+
+// Re-exported so downstream crates can name the map type without depending on `indexmap` directly.
+pub use indexmap::IndexMap as QueryMap;
+
+/// Map of query names (`--name` from the file) to the parsed `Query`, in the order they
+/// appeared in the source file.
+pub type Queries = IndexMap<String, Query>;
 
 #[derive(Debug, PartialEq)]
 enum LineType {
@@ -129,7 +147,12 @@ struct ParsedLine {
 /// let queries = parse_file("test.sql").expect("error parsing file");
 /// ```
 pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<Queries, ParseError> {
-    let file = File::open(path).map_err(|e| ParseError(format!("error reading file: {}", e)))?;
+    let file = File::open(&path).map_err(|e| {
+        ParseError::new(
+            ErrorKind::Io,
+            format!("error reading file '{}': {}", path.as_ref().display(), e),
+        )
+    })?;
     parse(file)
 }
 
@@ -150,28 +173,44 @@ pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<Queries, ParseError> {
 ///
 /// let queries = parse("--name: test\nSELECT 1;".as_bytes()).expect("error parsing bytes");
 /// ```
-
 pub fn parse<R: Read>(reader: R) -> Result<Queries, ParseError> {
     let mut name = String::new();
     let mut queries = Queries::new();
+    let mut offset: usize = 0;
+    // The span of the `-- name:` line that introduced each query, used to point at a query
+    // that turns out to have no body.
+    let mut name_spans: HashMap<String, Span> = HashMap::new();
 
     for (i, line) in BufReader::new(reader).lines().enumerate() {
-        let line = line.map_err(|e| ParseError(format!("error reading line {}: {}", i + 1, e)))?;
+        let line = line.map_err(|e| {
+            ParseError::new(ErrorKind::Io, format!("error reading line {}: {}", i + 1, e))
+        })?;
+
+        let line_start = offset;
+        offset += line.len() + 1; // +1 for the newline `lines()` strips.
+        // Point the span at the line's first non-blank character, so `start` and `column`
+        // describe the same position.
+        let indent = line.len() - line.trim_start().len();
+        let span = Span {
+            start: line_start + indent,
+            end: offset.saturating_sub(1),
+            line: i + 1,
+            column: indent + 1,
+        };
+
         let parsed_line = parse_line(&line);
 
         match parsed_line.line_type {
             LineType::Blank | LineType::Comment => continue,
             LineType::Query => {
                 if name.is_empty() {
-                    return Err(ParseError(format!(
-                        "query is missing the 'name' tag: {}",
-                        parsed_line.value
-                    )));
+                    return Err(ParseError::new(
+                        ErrorKind::MissingName,
+                        format!("query without a 'name' tag: '{}'", parsed_line.value),
+                    )
+                    .with_span(span));
                 }
-                let q = queries.entry(name.clone()).or_insert(Query {
-                    query: String::new(),
-                    tags: HashMap::new(),
-                });
+                let q = queries.entry(name.clone()).or_default();
                 if !q.query.is_empty() {
                     q.query.push(' ');
                 }
@@ -180,31 +219,34 @@ pub fn parse<R: Read>(reader: R) -> Result<Queries, ParseError> {
             LineType::Tag => {
                 if parsed_line.tag == TAG_NAME {
                     name = parsed_line.value.clone();
+                    name_spans.insert(name.clone(), span);
                     if queries.contains_key(&name) {
-                        return Err(ParseError(format!(
-                            "duplicate tag {} = {}",
-                            parsed_line.tag, parsed_line.value
-                        )));
+                        return Err(ParseError::new(
+                            ErrorKind::DuplicateName,
+                            format!("query '{}' is already defined", name),
+                        )
+                        .with_span(span)
+                        .with_query(name));
                     }
 
-                    queries.insert(
-                        name.clone(),
-                        Query {
-                            query: String::new(),
-                            tags: HashMap::new(),
-                        },
-                    );
+                    queries.insert(name.clone(), Query::default());
                 } else {
                     if !queries.contains_key(&name) {
-                        return Err(ParseError("'name' should be the first tag".to_string()));
+                        return Err(ParseError::new(
+                            ErrorKind::MissingName,
+                            "'name' should be the first tag",
+                        )
+                        .with_span(span));
                     }
 
                     let q = queries.get_mut(&name).unwrap();
                     if q.tags.contains_key(&parsed_line.tag) {
-                        return Err(ParseError(format!(
-                            "duplicate tag {} = {}",
-                            parsed_line.tag, parsed_line.value
-                        )));
+                        return Err(ParseError::new(
+                            ErrorKind::DuplicateTag,
+                            format!("duplicate tag '{}' for query '{}'", parsed_line.tag, name),
+                        )
+                        .with_span(span)
+                        .with_query(name.clone()));
                     }
                     q.tags.insert(parsed_line.tag, parsed_line.value);
                 }
@@ -212,9 +254,23 @@ pub fn parse<R: Read>(reader: R) -> Result<Queries, ParseError> {
         }
     }
 
+    for query in queries.values_mut() {
+        let (params, dialect) = params::extract_params(&query.query);
+        query.params = params;
+        query.placeholder_dialect = dialect;
+    }
+
     for (name, query) in &queries {
         if query.query.is_empty() {
-            return Err(ParseError(format!("'{}' is missing query", name)));
+            let mut err = ParseError::new(
+                ErrorKind::MissingQuery,
+                format!("'{}' is missing a query body", name),
+            )
+            .with_query(name.clone());
+            if let Some(&span) = name_spans.get(name) {
+                err = err.with_span(span);
+            }
+            return Err(err);
         }
     }
 
@@ -406,6 +462,34 @@ FROM comments;
         assert!(!result.is_ok());
     }
 
+    #[test]
+    fn test_query_params_are_populated() {
+        let sql = "-- name: get_user\nSELECT * FROM users WHERE id = $1;";
+        let queries = parse(sql.as_bytes()).unwrap();
+        let q = &queries["get_user"];
+        assert_eq!(
+            q.params,
+            vec![Param { name_or_index: "1".to_string(), kind: ParamStyle::Positional }]
+        );
+        assert_eq!(q.placeholder_dialect, Some(PlaceholderDialect::Positional));
+    }
+
+    #[test]
+    fn test_query_order_preserved() {
+        let sql = r#"
+-- name: third
+SELECT 3;
+-- name: first
+SELECT 1;
+-- name: second
+SELECT 2;
+"#;
+
+        let queries = parse(sql.as_bytes()).unwrap();
+        let names: Vec<&str> = queries.keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["third", "first", "second"]);
+    }
+
     #[test]
     fn test_parse_bytes() {
         let result = parse(
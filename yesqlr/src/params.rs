@@ -0,0 +1,209 @@
+//! Bind-parameter introspection, so callers can learn what a query expects to bind without
+//! running it.
+
+/// The placeholder style a [`Param`] was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamStyle {
+    /// `$1`, `$2`, ...
+    Positional,
+    /// `?`
+    Anonymous,
+    /// `:name` or `@name`
+    Named,
+}
+
+/// A single bind parameter detected in a query body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    /// The placeholder's index (for `Positional`) or name (for `Named`), as text.
+    /// `Anonymous` placeholders carry `"?"` since they have no identity of their own.
+    pub name_or_index: String,
+    pub kind: ParamStyle,
+}
+
+/// The overall placeholder dialect used by a query, or `Mixed` if more than one style is
+/// present, which most drivers reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderDialect {
+    Positional,
+    Anonymous,
+    Named,
+    Mixed,
+}
+
+/// Scans a query body for bind placeholders, skipping over quoted string/identifier
+/// literals and `--`/`/* */` comments so a literal `'$5'` isn't mistaken for a bind.
+///
+/// Positional placeholders are deduplicated by index; named placeholders are deduplicated
+/// by name, keeping first-seen order in both cases.
+pub fn extract_params(sql: &str) -> (Vec<Param>, Option<PlaceholderDialect>) {
+    let chars: Vec<char> = sql.chars().collect();
+    let len = chars.len();
+    let mut i = 0;
+
+    let mut params = Vec::new();
+    let mut seen_positions = std::collections::HashSet::new();
+    let mut seen_names = std::collections::HashSet::new();
+    let mut saw_positional = false;
+    let mut saw_anonymous = false;
+    let mut saw_named = false;
+
+    while i < len {
+        let c = chars[i];
+
+        // Skip single-quoted string literals.
+        if c == '\'' {
+            i += 1;
+            while i < len && chars[i] != '\'' {
+                i += 1;
+            }
+            i = (i + 1).min(len);
+            continue;
+        }
+
+        // Skip double-quoted identifiers.
+        if c == '"' {
+            i += 1;
+            while i < len && chars[i] != '"' {
+                i += 1;
+            }
+            i = (i + 1).min(len);
+            continue;
+        }
+
+        // Skip `--` line comments.
+        if c == '-' && chars.get(i + 1) == Some(&'-') {
+            while i < len && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+
+        // Skip `/* ... */` block comments.
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i + 1 < len && !(chars[i] == '*' && chars[i + 1] == '/') {
+                i += 1;
+            }
+            i = (i + 2).min(len);
+            continue;
+        }
+
+        // Positional: $1, $2, ...
+        if c == '$' {
+            let start = i + 1;
+            let mut j = start;
+            while j < len && chars[j].is_ascii_digit() {
+                j += 1;
+            }
+            if j > start {
+                let index: String = chars[start..j].iter().collect();
+                saw_positional = true;
+                if seen_positions.insert(index.clone()) {
+                    params.push(Param { name_or_index: index, kind: ParamStyle::Positional });
+                }
+                i = j;
+                continue;
+            }
+        }
+
+        // Anonymous: ?
+        if c == '?' {
+            saw_anonymous = true;
+            params.push(Param {
+                name_or_index: "?".to_string(),
+                kind: ParamStyle::Anonymous,
+            });
+            i += 1;
+            continue;
+        }
+
+        // Named: :name or @name. `::` is a Postgres cast, not a placeholder, so skip it.
+        if c == ':' || c == '@' {
+            if c == ':' && chars.get(i + 1) == Some(&':') {
+                i += 2;
+                continue;
+            }
+            let start = i + 1;
+            let mut j = start;
+            while j < len && (chars[j].is_alphanumeric() || chars[j] == '_') {
+                j += 1;
+            }
+            if j > start {
+                let name: String = chars[start..j].iter().collect();
+                saw_named = true;
+                if seen_names.insert(name.clone()) {
+                    params.push(Param { name_or_index: name, kind: ParamStyle::Named });
+                }
+                i = j;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+
+    let dialect = match (saw_positional, saw_anonymous, saw_named) {
+        (false, false, false) => None,
+        (true, false, false) => Some(PlaceholderDialect::Positional),
+        (false, true, false) => Some(PlaceholderDialect::Anonymous),
+        (false, false, true) => Some(PlaceholderDialect::Named),
+        _ => Some(PlaceholderDialect::Mixed),
+    };
+
+    (params, dialect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positional_params_deduped_in_order() {
+        let (params, dialect) = extract_params("SELECT * FROM users WHERE id = $2 OR id = $1 OR id = $2");
+        assert_eq!(
+            params,
+            vec![
+                Param { name_or_index: "2".to_string(), kind: ParamStyle::Positional },
+                Param { name_or_index: "1".to_string(), kind: ParamStyle::Positional },
+            ]
+        );
+        assert_eq!(dialect, Some(PlaceholderDialect::Positional));
+    }
+
+    #[test]
+    fn test_named_params_in_first_seen_order() {
+        let (params, dialect) = extract_params("INSERT INTO users (name, email) VALUES (:name, :email)");
+        assert_eq!(
+            params,
+            vec![
+                Param { name_or_index: "name".to_string(), kind: ParamStyle::Named },
+                Param { name_or_index: "email".to_string(), kind: ParamStyle::Named },
+            ]
+        );
+        assert_eq!(dialect, Some(PlaceholderDialect::Named));
+    }
+
+    #[test]
+    fn test_anonymous_params() {
+        let (params, dialect) = extract_params("SELECT * FROM users WHERE id = ? AND name = ?");
+        assert_eq!(params.len(), 2);
+        assert!(params.iter().all(|p| p.kind == ParamStyle::Anonymous));
+        assert_eq!(dialect, Some(PlaceholderDialect::Anonymous));
+    }
+
+    #[test]
+    fn test_placeholders_in_literals_and_comments_are_ignored() {
+        let (params, dialect) = extract_params(
+            "-- get a user, e.g. id=$5\nSELECT * FROM users WHERE email = '$5' /* @unused */",
+        );
+        assert!(params.is_empty());
+        assert_eq!(dialect, None);
+    }
+
+    #[test]
+    fn test_mixed_dialect_is_flagged() {
+        let (_, dialect) = extract_params("SELECT * FROM users WHERE id = $1 AND name = ?");
+        assert_eq!(dialect, Some(PlaceholderDialect::Mixed));
+    }
+}
@@ -0,0 +1,194 @@
+//! Splitting a query body into individual statements, for drivers that only accept one
+//! statement per prepare.
+
+/// Splits a query body on top-level semicolons, respecting single/double-quoted strings,
+/// Postgres dollar-quoted bodies (`$tag$ ... $tag$`), `--` line comments, and `/* ... */`
+/// block comments, so a semicolon inside a string literal, a PL/pgSQL function body
+/// (already dollar-quoted by convention), or a comment does not split it.
+///
+/// Empty trailing fragments (e.g. after the final `;`) are dropped.
+pub fn split_statements(sql: &str) -> Vec<String> {
+    let chars: Vec<char> = sql.chars().collect();
+    let len = chars.len();
+    let mut i = 0;
+    let mut start = 0;
+    let mut statements = Vec::new();
+
+    let mut in_single = false;
+    let mut in_double = false;
+    let mut dollar_tag: Option<String> = None;
+
+    while i < len {
+        let c = chars[i];
+
+        if in_single {
+            if c == '\'' {
+                in_single = false;
+            }
+            i += 1;
+            continue;
+        }
+        if in_double {
+            if c == '"' {
+                in_double = false;
+            }
+            i += 1;
+            continue;
+        }
+        if let Some(tag) = dollar_tag.clone() {
+            if c == '$' && matches_dollar_tag(&chars, i, &tag) {
+                i += tag.len() + 2;
+                dollar_tag = None;
+            } else {
+                i += 1;
+            }
+            continue;
+        }
+
+        // `--` line comments.
+        if c == '-' && chars.get(i + 1) == Some(&'-') {
+            while i < len && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+
+        // `/* ... */` block comments.
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i + 1 < len && !(chars[i] == '*' && chars[i + 1] == '/') {
+                i += 1;
+            }
+            i = (i + 2).min(len);
+            continue;
+        }
+
+        if c == '\'' {
+            in_single = true;
+            i += 1;
+            continue;
+        }
+        if c == '"' {
+            in_double = true;
+            i += 1;
+            continue;
+        }
+        if c == '$' {
+            if let Some(tag) = read_dollar_tag(&chars, i) {
+                i += tag.len() + 2;
+                dollar_tag = Some(tag);
+                continue;
+            }
+        }
+
+        if c == ';' {
+            statements.push(chars[start..=i].iter().collect::<String>());
+            i += 1;
+            start = i;
+            continue;
+        }
+
+        i += 1;
+    }
+
+    if start < len {
+        let tail: String = chars[start..len].iter().collect();
+        if !tail.trim().is_empty() {
+            statements.push(tail);
+        }
+    }
+
+    statements
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Whether `chars[i]` starts a `$tag$` opening delimiter, returning the tag (without the
+/// surrounding `$`s) if so.
+fn read_dollar_tag(chars: &[char], i: usize) -> Option<String> {
+    let mut j = i + 1;
+    while j < chars.len() && (chars[j].is_alphanumeric() || chars[j] == '_') {
+        j += 1;
+    }
+    if j < chars.len() && chars[j] == '$' {
+        Some(chars[i + 1..j].iter().collect())
+    } else {
+        None
+    }
+}
+
+/// Whether `chars[i]` starts the closing `$tag$` matching `tag`.
+fn matches_dollar_tag(chars: &[char], i: usize, tag: &str) -> bool {
+    let end = i + 1 + tag.len();
+    end < chars.len()
+        && chars[i + 1..end].iter().collect::<String>() == tag
+        && chars[end] == '$'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_split() {
+        let stmts = split_statements("SELECT 1; SELECT 2;");
+        assert_eq!(stmts, vec!["SELECT 1;", "SELECT 2;"]);
+    }
+
+    #[test]
+    fn test_trailing_fragment_without_semicolon() {
+        let stmts = split_statements("SELECT 1; SELECT 2");
+        assert_eq!(stmts, vec!["SELECT 1;", "SELECT 2"]);
+    }
+
+    #[test]
+    fn test_semicolon_in_string_literal_is_not_a_split() {
+        let stmts = split_statements("INSERT INTO logs (msg) VALUES ('a;b'); SELECT 1;");
+        assert_eq!(stmts, vec!["INSERT INTO logs (msg) VALUES ('a;b');", "SELECT 1;"]);
+    }
+
+    #[test]
+    fn test_dollar_quoted_body_is_not_split() {
+        let sql = "CREATE FUNCTION f() RETURNS void AS $$ SELECT 1; SELECT 2; $$ LANGUAGE sql; SELECT 3;";
+        let stmts = split_statements(sql);
+        assert_eq!(stmts.len(), 2);
+        assert!(stmts[0].starts_with("CREATE FUNCTION"));
+        assert_eq!(stmts[1], "SELECT 3;");
+    }
+
+    #[test]
+    fn test_tagged_dollar_quote_is_not_split() {
+        let sql = "DO $body$ BEGIN PERFORM 1; END; $body$; SELECT 1;";
+        let stmts = split_statements(sql);
+        assert_eq!(stmts, vec!["DO $body$ BEGIN PERFORM 1; END; $body$;", "SELECT 1;"]);
+    }
+
+    #[test]
+    fn test_semicolon_in_line_comment_is_not_a_split() {
+        let stmts = split_statements("SELECT 1 -- foo; bar ; SELECT 2;");
+        assert_eq!(stmts, vec!["SELECT 1 -- foo; bar ; SELECT 2;"]);
+    }
+
+    #[test]
+    fn test_semicolon_in_block_comment_is_not_a_split() {
+        let stmts = split_statements("SELECT 1; /* foo; bar */ SELECT 2;");
+        assert_eq!(stmts, vec!["SELECT 1;", "/* foo; bar */ SELECT 2;"]);
+    }
+
+    #[test]
+    fn test_transaction_block_splits() {
+        let sql = "BEGIN; CREATE TABLE x (id INT); INSERT INTO x (id) VALUES (1); COMMIT;";
+        let stmts = split_statements(sql);
+        assert_eq!(
+            stmts,
+            vec![
+                "BEGIN;",
+                "CREATE TABLE x (id INT);",
+                "INSERT INTO x (id) VALUES (1);",
+                "COMMIT;",
+            ]
+        );
+    }
+}
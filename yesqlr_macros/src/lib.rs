@@ -2,9 +2,9 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Lit, Meta};
+use syn::{parse_macro_input, DeriveInput, Lit, Meta, NestedMeta};
 
-#[proc_macro_derive(ScanQueries, attributes(name))]
+#[proc_macro_derive(ScanQueries, attributes(name, required, tags, yesqlr))]
 pub fn scan_queries_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -16,6 +16,15 @@ pub fn scan_queries_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// A single field mapped to a named query.
+struct FieldQuery<'a> {
+    field: &'a syn::Ident,
+    query_name: String,
+    required: bool,
+    /// Tag key/value pairs from `#[tags(key = "value", ...)]` that the query must carry.
+    tags: Vec<(String, String)>,
+}
+
 fn generate_try_from(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let fields = if let syn::Data::Struct(ref data_struct) = input.data {
         &data_struct.fields
@@ -26,48 +35,122 @@ fn generate_try_from(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
         ));
     };
 
-    let mut map = Vec::new();
     let name = &input.ident;
+    let deny_unknown = container_has_deny_unknown(input);
 
+    let mut fields_map = Vec::new();
     for field in fields.iter() {
         let field_name = field.ident.as_ref().unwrap();
 
         // Use the field's name as the key by default.
         let mut query_name = field_name.to_string();
+        let mut required = false;
+        let mut tags = Vec::new();
 
         for attr in &field.attrs {
-            // If there's a #[name = "..."] attribute, use that as the name.
             if attr.path.is_ident("name") {
                 if let Ok(Meta::NameValue(meta)) = attr.parse_meta() {
                     if let Lit::Str(lit_str) = meta.lit {
                         query_name = lit_str.value();
                     }
                 }
+            } else if attr.path.is_ident("required") {
+                required = true;
+            } else if attr.path.is_ident("tags") {
+                if let Ok(Meta::List(list)) = attr.parse_meta() {
+                    for nested in list.nested {
+                        if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                            if let (Some(key), Lit::Str(value)) =
+                                (nv.path.get_ident(), nv.lit)
+                            {
+                                tags.push((key.to_string(), value.value()));
+                            }
+                        }
+                    }
+                }
             }
         }
 
-        map.push((field_name, query_name));
+        fields_map.push(FieldQuery { field: field_name, query_name, required, tags });
     }
 
-    let extract_fields = map.iter().map(|(field, key)| {
+    let claimed_names = fields_map.iter().map(|f| f.query_name.as_str());
+
+    let deny_unknown_check = if deny_unknown {
         quote! {
-            #field: {
-                if let Some(query) = queries.remove(#key) {
-                    query
-                } else {
-                    Default::default()
-                }
+            let claimed: std::collections::HashSet<&str> =
+                [#(#claimed_names),*].into_iter().collect();
+            let unknown: Vec<String> = queries
+                .keys()
+                .filter(|k| !claimed.contains(k.as_str()))
+                .cloned()
+                .collect();
+            if !unknown.is_empty() {
+                return Err(format!(
+                    "queries not claimed by any field: {}",
+                    unknown.join(", ")
+                ));
             }
         }
+    } else {
+        quote! {}
+    };
+
+    let extractions = fields_map.iter().map(|f| {
+        let field = f.field;
+        let query_name = &f.query_name;
+        let required = f.required;
+        let (tag_keys, tag_values): (Vec<_>, Vec<_>) = f.tags.iter().cloned().unzip();
+
+        quote! {
+            let #field = match queries.remove(#query_name) {
+                Some(query) => {
+                    #(
+                        if query.tags.get(#tag_keys).map(String::as_str) != Some(#tag_values) {
+                            __yesqlr_tag_errors.push(format!(
+                                "query '{}' is missing tag {} = \"{}\"",
+                                #query_name, #tag_keys, #tag_values
+                            ));
+                        }
+                    )*
+                    Some(query)
+                }
+                None => {
+                    if #required {
+                        __yesqlr_missing.push(#query_name.to_string());
+                    }
+                    None
+                }
+            };
+        }
     });
 
+    let field_idents = fields_map.iter().map(|f| f.field);
+
     let expanded = quote! {
         impl std::convert::TryFrom<yesqlr::Queries> for #name {
             type Error = String;
 
             fn try_from(mut queries: yesqlr::Queries) -> Result<Self, Self::Error> {
+                #deny_unknown_check
+
+                let mut __yesqlr_missing: Vec<String> = Vec::new();
+                let mut __yesqlr_tag_errors: Vec<String> = Vec::new();
+
+                #(#extractions)*
+
+                if !__yesqlr_missing.is_empty() {
+                    return Err(format!(
+                        "missing required queries: {}",
+                        __yesqlr_missing.join(", ")
+                    ));
+                }
+                if !__yesqlr_tag_errors.is_empty() {
+                    return Err(__yesqlr_tag_errors.join("; "));
+                }
+
                 Ok(Self {
-                    #(#extract_fields,)*
+                    #(#field_idents: #field_idents.unwrap_or_default(),)*
                 })
             }
         }
@@ -75,3 +158,101 @@ fn generate_try_from(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
 
     Ok(expanded)
 }
+
+/// Whether the struct carries a container-level `#[yesqlr(deny_unknown)]` attribute.
+fn container_has_deny_unknown(input: &DeriveInput) -> bool {
+    for attr in &input.attrs {
+        if !attr.path.is_ident("yesqlr") {
+            continue;
+        }
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            for nested in list.nested {
+                if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                    if path.is_ident("deny_unknown") {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Generates, for each field mapped to a named query, a `<field>_query(binds)` method
+/// seeded with the stored SQL string: `sqlx::query_with(...)` by default, or
+/// `sqlx::query_as_with::<_, T, _>(...)` when the field carries `#[returns(T)]`. `binds` is
+/// a `sqlx::any::AnyArguments` the caller builds with `sqlx::Arguments::add`, so a query
+/// binding mixed types (e.g. an `i64` id and a `String` email) can be called without every
+/// bind sharing one concrete type. Requires the `sqlx` feature.
+#[cfg(feature = "sqlx")]
+#[proc_macro_derive(SqlxQueries, attributes(name, returns))]
+pub fn sqlx_queries_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = match generate_sqlx_methods(&input) {
+        Ok(tokens) => tokens,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[cfg(feature = "sqlx")]
+fn generate_sqlx_methods(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = if let syn::Data::Struct(ref data_struct) = input.data {
+        &data_struct.fields
+    } else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "SqlxQueries can only be derived for structs",
+        ));
+    };
+
+    let name = &input.ident;
+    let mut methods = Vec::new();
+
+    for field in fields.iter() {
+        let field_name = field.ident.as_ref().unwrap();
+        let method_name = quote::format_ident!("{}_query", field_name);
+
+        let mut returns: Option<syn::Path> = None;
+        for attr in &field.attrs {
+            if attr.path.is_ident("returns") {
+                returns = Some(attr.parse_args()?);
+            }
+        }
+
+        // The number and types of binds a query needs are only known once its SQL is
+        // parsed at runtime (see `Query::params`), so callers build their own
+        // `AnyArguments` via `sqlx::Arguments::add`, pushing binds of different types in
+        // the same call — a fixed `A: Encode<Any>` generic would force every bind to
+        // share one concrete type, which a query like `get_user(id, email)` can't satisfy.
+        let method = if let Some(ty) = returns {
+            quote! {
+                pub fn #method_name<'q>(
+                    &'q self,
+                    binds: sqlx::any::AnyArguments<'q>,
+                ) -> sqlx::query::QueryAs<'q, sqlx::Any, #ty, sqlx::any::AnyArguments<'q>> {
+                    sqlx::query_as_with::<_, #ty, _>(&self.#field_name.query, binds)
+                }
+            }
+        } else {
+            quote! {
+                pub fn #method_name<'q>(
+                    &'q self,
+                    binds: sqlx::any::AnyArguments<'q>,
+                ) -> sqlx::query::Query<'q, sqlx::Any, sqlx::any::AnyArguments<'q>> {
+                    sqlx::query_with(&self.#field_name.query, binds)
+                }
+            }
+        };
+
+        methods.push(method);
+    }
+
+    Ok(quote! {
+        impl #name {
+            #(#methods)*
+        }
+    })
+}
@@ -24,4 +24,57 @@ mod tests {
         assert_eq!(q.simple_two.query, "SELECT * FROM simple2;");
         assert_eq!(q.another.query, "");
     }
+
+    #[test]
+    fn test_required_field_missing() {
+        let result = yesqlr::parse("--name: simple\nSELECT * FROM simple;".as_bytes()).unwrap();
+
+        #[derive(Debug, Default, yesqlr_macros::ScanQueries)]
+        #[allow(dead_code)]
+        struct Q {
+            simple: yesqlr::Query,
+
+            #[required]
+            missing: yesqlr::Query,
+        }
+
+        let err = Q::try_from(result).expect_err("expected a missing required query to error");
+        assert!(err.contains("missing"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_deny_unknown_rejects_unclaimed_queries() {
+        let result = yesqlr::parse(
+            "--name: simple\nSELECT * FROM simple;\n--name: typo\nSELECT 1;".as_bytes(),
+        )
+        .unwrap();
+
+        #[derive(Debug, Default, yesqlr_macros::ScanQueries)]
+        #[yesqlr(deny_unknown)]
+        #[allow(dead_code)]
+        struct Q {
+            simple: yesqlr::Query,
+        }
+
+        let err = Q::try_from(result).expect_err("expected an unclaimed query to error");
+        assert!(err.contains("typo"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_tags_attribute_validates_tag_values() {
+        let result = yesqlr::parse(
+            "--name: simple\n-- raw: false\nSELECT * FROM simple;".as_bytes(),
+        )
+        .unwrap();
+
+        #[derive(Debug, Default, yesqlr_macros::ScanQueries)]
+        #[allow(dead_code)]
+        struct Q {
+            #[tags(raw = "true")]
+            simple: yesqlr::Query,
+        }
+
+        let err = Q::try_from(result).expect_err("expected a tag mismatch to error");
+        assert!(err.contains("raw"), "unexpected error: {}", err);
+    }
 }
@@ -0,0 +1,37 @@
+#![cfg(feature = "sqlx")]
+
+#[derive(sqlx::FromRow)]
+#[allow(dead_code)]
+struct User {
+    id: i64,
+    name: String,
+}
+
+#[derive(Default, yesqlr_macros::ScanQueries, yesqlr_macros::SqlxQueries)]
+struct Queries {
+    get_user: yesqlr::Query,
+
+    #[returns(User)]
+    list_users: yesqlr::Query,
+}
+
+#[test]
+fn test_generated_methods_compile() {
+    use sqlx::Arguments;
+
+    let parsed = yesqlr::parse(
+        "-- name: get_user\nSELECT * FROM users WHERE id = $1 AND email = $2;\n-- name: list_users\nSELECT * FROM users;"
+            .as_bytes(),
+    )
+    .unwrap();
+    let q = Queries::try_from(parsed).unwrap();
+
+    // Binds of different types (an i64 id and a String email) in the same call, which a
+    // single generic bind type could not have expressed.
+    let mut binds = sqlx::any::AnyArguments::default();
+    binds.add(1_i64).unwrap();
+    binds.add("a@b.com".to_string()).unwrap();
+    let _query = q.get_user_query(binds);
+
+    let _query_as = q.list_users_query(sqlx::any::AnyArguments::default());
+}